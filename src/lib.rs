@@ -4,13 +4,38 @@
 #![cfg(unix)]
 
 mod sys {
-    pub use libc::{sigaddset, sigdelset, sigemptyset, sigfillset, sigismember, sigset_t};
+    pub use libc::{
+        pthread_sigmask, sigaddset, sigdelset, sigemptyset, sigfillset, sigismember, sigpending,
+        sigprocmask, sigset_t, sigwait,
+    };
+
+    // `sigtimedwait`/`sigwaitinfo` do not exist on Darwin; FreeBSD does provide
+    // them.
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    pub use libc::{sigtimedwait, sigwaitinfo};
 }
 
 use core::fmt;
 use core::mem::MaybeUninit;
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+use core::time::Duration;
 use libc::c_int;
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+unsafe fn errno() -> c_int {
+    *libc::__errno_location()
+}
+
+#[cfg(any(
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "dragonfly"
+))]
+unsafe fn errno() -> c_int {
+    *libc::__error()
+}
+
 pub use sys::sigset_t;
 
 pub struct SigSet {
@@ -90,7 +115,346 @@ impl SigSet {
     }
 }
 
+/// The largest signal number worth probing when enumerating a [`SigSet`].
+///
+/// On Linux this is the top of the real-time range; elsewhere it is a fixed
+/// bound of 65. Numbers that `sigismember` rejects with `EINVAL` are simply
+/// skipped, so an over-estimate is harmless.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn candidate_max() -> c_int {
+    libc::SIGRTMAX()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn candidate_max() -> c_int {
+    // `libc` does not expose `NSIG` on the BSDs or macOS, so use a fixed upper
+    // bound comfortably above the highest classic signal; numbers in the gap
+    // that aren't valid signals are rejected by `sigismember` and skipped.
+    65
+}
+
+/// An iterator over the signals that are members of a [`SigSet`].
+///
+/// Created by [`SigSet::iter`].
+pub struct Iter<'a> {
+    set: &'a SigSet,
+    next: c_int,
+    max: c_int,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = Signal;
+
+    fn next(&mut self) -> Option<Signal> {
+        while self.next <= self.max {
+            let num = self.next;
+            self.next += 1;
+
+            // `sigismember` returns 1 for a member, 0 for a non-member and -1
+            // (setting `EINVAL`) for numbers that aren't valid signals; only
+            // the first is a hit.
+            if unsafe { sys::sigismember(self.set.as_ptr(), num) } == 1 {
+                return Some(Signal::new(num));
+            }
+        }
+
+        None
+    }
+}
+
+impl SigSet {
+    /// Iterate over every signal that is currently a member of this set.
+    ///
+    /// Candidate signal numbers are probed from `1` up to `SIGRTMAX` on Linux
+    /// and Android (up to 65 on other platforms) and those reported as
+    /// members by `sigismember` are yielded.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            set: self,
+            next: 1,
+            max: candidate_max(),
+        }
+    }
+
+    /// Add every POSIX real-time signal, from `SIGRTMIN` through `SIGRTMAX`
+    /// inclusive, to this set.
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub fn add_all_realtime(&mut self) {
+        let max = libc::SIGRTMAX();
+        for num in libc::SIGRTMIN()..=max {
+            unsafe {
+                sys::sigaddset(self.as_mut_ptr(), num);
+            }
+        }
+    }
+}
+
+impl core::iter::FromIterator<Signal> for SigSet {
+    fn from_iter<T: IntoIterator<Item = Signal>>(iter: T) -> Self {
+        let mut set = SigSet::empty();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Signal> for SigSet {
+    fn extend<T: IntoIterator<Item = Signal>>(&mut self, iter: T) {
+        for sig in iter {
+            unsafe {
+                sys::sigaddset(self.as_mut_ptr(), sig.into_raw());
+            }
+        }
+    }
+}
+
+impl core::ops::BitOr for &SigSet {
+    type Output = SigSet;
+
+    fn bitor(self, rhs: &SigSet) -> SigSet {
+        self.iter().chain(rhs.iter()).collect()
+    }
+}
+
+impl core::ops::BitAnd for &SigSet {
+    type Output = SigSet;
+
+    fn bitand(self, rhs: &SigSet) -> SigSet {
+        self.iter()
+            .filter(|sig| rhs.contains(*sig).unwrap_or(false))
+            .collect()
+    }
+}
+
+impl core::ops::Sub for &SigSet {
+    type Output = SigSet;
+
+    fn sub(self, rhs: &SigSet) -> SigSet {
+        self.iter()
+            .filter(|sig| !rhs.contains(*sig).unwrap_or(false))
+            .collect()
+    }
+}
+
+/// How a signal mask update combines with the current mask.
+///
+/// Mirrors the `how` argument of `pthread_sigmask(3)` and `sigprocmask(2)`.
 #[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SigmaskHow {
+    /// Add the signals in the set to the current mask (`SIG_BLOCK`).
+    Block,
+    /// Remove the signals in the set from the current mask (`SIG_UNBLOCK`).
+    Unblock,
+    /// Replace the current mask with the set (`SIG_SETMASK`).
+    SetMask,
+}
+
+impl SigmaskHow {
+    const fn into_raw(self) -> c_int {
+        match self {
+            SigmaskHow::Block => libc::SIG_BLOCK,
+            SigmaskHow::Unblock => libc::SIG_UNBLOCK,
+            SigmaskHow::SetMask => libc::SIG_SETMASK,
+        }
+    }
+}
+
+impl SigSet {
+    /// Update the calling thread's signal mask with this set, using `how` to
+    /// decide how the set combines with the mask already installed.
+    ///
+    /// Wraps `pthread_sigmask(3)`.
+    pub fn thread_set_mask(&self, how: SigmaskHow) -> Result<(), InvalidSignalError> {
+        unsafe {
+            let ret = sys::pthread_sigmask(how.into_raw(), self.as_ptr(), core::ptr::null_mut());
+
+            if ret != 0 {
+                Err(InvalidSignalError(()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Block the signals in this set in the calling thread (`SIG_BLOCK`).
+    pub fn thread_block(&self) -> Result<(), InvalidSignalError> {
+        self.thread_set_mask(SigmaskHow::Block)
+    }
+
+    /// Unblock the signals in this set in the calling thread (`SIG_UNBLOCK`).
+    pub fn thread_unblock(&self) -> Result<(), InvalidSignalError> {
+        self.thread_set_mask(SigmaskHow::Unblock)
+    }
+
+    /// Update the whole process's signal mask with this set.
+    ///
+    /// Wraps `sigprocmask(2)`. In a multithreaded program `thread_set_mask`
+    /// should usually be preferred.
+    pub fn process_set_mask(&self, how: SigmaskHow) -> Result<(), InvalidSignalError> {
+        unsafe {
+            let ret = sys::sigprocmask(how.into_raw(), self.as_ptr(), core::ptr::null_mut());
+
+            if ret < 0 {
+                Err(InvalidSignalError(()))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    /// Block the signals in this set for the duration of the returned guard,
+    /// restoring the previous mask when it is dropped.
+    ///
+    /// The previous mask is captured through the `oldset` out-parameter of
+    /// `pthread_sigmask(3)` and reinstalled with `SIG_SETMASK` on `Drop`,
+    /// so a critical section can be protected without manually pairing the
+    /// block and unblock calls.
+    pub fn block_scoped(&self) -> SigMaskGuard {
+        unsafe {
+            let mut oldset = MaybeUninit::uninit();
+            sys::pthread_sigmask(SigmaskHow::Block.into_raw(), self.as_ptr(), oldset.as_mut_ptr());
+            SigMaskGuard {
+                oldset: oldset.assume_init(),
+            }
+        }
+    }
+}
+
+impl SigSet {
+    /// Block until one of the signals in this set is delivered, consuming it
+    /// and returning which one it was.
+    ///
+    /// Wraps `sigwait(3)`. The set should already be blocked (see
+    /// [`SigSet::thread_block`]) in every thread, otherwise the signal may be
+    /// handled asynchronously instead.
+    pub fn wait(&self) -> Result<Signal, InvalidSignalError> {
+        unsafe {
+            let mut sig: c_int = 0;
+            let ret = sys::sigwait(self.as_ptr(), &mut sig);
+
+            if ret != 0 {
+                Err(InvalidSignalError(()))
+            } else {
+                Ok(Signal::new(sig))
+            }
+        }
+    }
+
+    /// Wait for one of the signals in this set for at most `timeout`,
+    /// returning `Ok(None)` if the timeout elapses first.
+    ///
+    /// Wraps `sigtimedwait(2)`; an `EAGAIN` result (the timeout expiring) is
+    /// reported as `Ok(None)` rather than an error.
+    ///
+    /// Not available on Darwin, which does not provide `sigtimedwait`.
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    pub fn wait_timeout(&self, timeout: Duration) -> Result<Option<Signal>, InvalidSignalError> {
+        unsafe {
+            let ts = libc::timespec {
+                tv_sec: timeout.as_secs() as libc::time_t,
+                tv_nsec: timeout.subsec_nanos() as _,
+            };
+            let ret = sys::sigtimedwait(self.as_ptr(), core::ptr::null_mut(), &ts);
+
+            if ret < 0 {
+                if errno() == libc::EAGAIN {
+                    Ok(None)
+                } else {
+                    Err(InvalidSignalError(()))
+                }
+            } else {
+                Ok(Some(Signal::new(ret)))
+            }
+        }
+    }
+
+    /// Block until one of the signals in this set is delivered, returning both
+    /// the signal and its accompanying [`SigInfo`] payload.
+    ///
+    /// Wraps `sigwaitinfo(2)`.
+    ///
+    /// Not available on Darwin, which does not provide `sigwaitinfo`.
+    #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+    pub fn wait_info(&self) -> Result<SigInfo, InvalidSignalError> {
+        unsafe {
+            let mut info = MaybeUninit::<libc::siginfo_t>::uninit();
+            let ret = sys::sigwaitinfo(self.as_ptr(), info.as_mut_ptr());
+
+            if ret < 0 {
+                Err(InvalidSignalError(()))
+            } else {
+                Ok(SigInfo(info.assume_init()))
+            }
+        }
+    }
+}
+
+/// The `siginfo_t` payload delivered alongside a synchronously waited signal.
+///
+/// Returned by [`SigSet::wait_info`].
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+pub struct SigInfo(libc::siginfo_t);
+
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+impl SigInfo {
+    /// The signal that was delivered.
+    pub fn signal(&self) -> Signal {
+        Signal::new(self.0.si_signo)
+    }
+
+    /// The signal code (`si_code`), describing how the signal was sent.
+    pub fn code(&self) -> c_int {
+        self.0.si_code
+    }
+
+    /// The pid of the process that sent the signal.
+    pub fn sender_pid(&self) -> libc::pid_t {
+        unsafe { self.0.si_pid() }
+    }
+
+    /// The raw `siginfo_t` this payload wraps.
+    pub fn as_raw(&self) -> &libc::siginfo_t {
+        &self.0
+    }
+}
+
+/// The set of signals currently pending on the calling thread.
+///
+/// Wraps `sigpending(2)`.
+pub fn sigpending() -> Result<SigSet, InvalidSignalError> {
+    unsafe {
+        let mut set = MaybeUninit::uninit();
+        let ret = sys::sigpending(set.as_mut_ptr());
+
+        if ret < 0 {
+            Err(InvalidSignalError(()))
+        } else {
+            Ok(SigSet {
+                set: set.assume_init(),
+            })
+        }
+    }
+}
+
+/// Restores a previously saved signal mask when dropped.
+///
+/// Created by [`SigSet::block_scoped`].
+pub struct SigMaskGuard {
+    oldset: sigset_t,
+}
+
+impl Drop for SigMaskGuard {
+    fn drop(&mut self) {
+        unsafe {
+            sys::pthread_sigmask(
+                SigmaskHow::SetMask.into_raw(),
+                &self.oldset as *const sigset_t,
+                core::ptr::null_mut(),
+            );
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Signal(c_int);
 
 impl Signal {
@@ -103,6 +467,111 @@ impl Signal {
     }
 }
 
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Signal {
+    /// The first POSIX real-time signal, `SIGRTMIN`.
+    pub fn rt_min() -> Signal {
+        Signal::new(libc::SIGRTMIN())
+    }
+
+    /// The last POSIX real-time signal, `SIGRTMAX`.
+    pub fn rt_max() -> Signal {
+        Signal::new(libc::SIGRTMAX())
+    }
+
+    /// The real-time signal `SIGRTMIN + offset`.
+    ///
+    /// Returns an error if `offset` is negative or pushes the result past
+    /// `SIGRTMAX`.
+    pub fn rt(offset: c_int) -> Result<Signal, InvalidSignalError> {
+        let min = libc::SIGRTMIN();
+        let max = libc::SIGRTMAX();
+
+        match min.checked_add(offset) {
+            Some(num) if offset >= 0 && num <= max => Ok(Signal::new(num)),
+            _ => Err(InvalidSignalError(())),
+        }
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(name) = self.as_str() {
+            return fmt.write_str(name);
+        }
+
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        {
+            let min = libc::SIGRTMIN();
+            let max = libc::SIGRTMAX();
+            if self.0 >= min && self.0 <= max {
+                return match self.0 - min {
+                    0 => fmt.write_str("SIGRTMIN"),
+                    offset => write!(fmt, "SIGRTMIN+{}", offset),
+                };
+            }
+        }
+
+        write!(fmt, "SIG{}", self.0)
+    }
+}
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "Signal({})", self)
+    }
+}
+
+impl core::str::FromStr for Signal {
+    type Err = InvalidSignalError;
+
+    /// Parses a signal by name, accepting both the `SIGTERM` and `TERM`
+    /// spellings as well as, on Linux and Android, the real-time
+    /// `SIGRTMIN`/`SIGRTMIN+n` forms.
+    fn from_str(s: &str) -> Result<Signal, InvalidSignalError> {
+        if let Some(sig) = Signal::from_name(s) {
+            return Ok(sig);
+        }
+
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        {
+            let bare = s.strip_prefix("SIG").unwrap_or(s);
+            if let Some(rest) = bare.strip_prefix("RTMIN") {
+                let offset = match rest {
+                    "" => 0,
+                    _ => rest
+                        .strip_prefix('+')
+                        .and_then(|n| n.parse::<c_int>().ok())
+                        .ok_or(InvalidSignalError(()))?,
+                };
+                return Signal::rt(offset);
+            }
+        }
+
+        Err(InvalidSignalError(()))
+    }
+}
+
+impl core::convert::TryFrom<c_int> for Signal {
+    type Error = InvalidSignalError;
+
+    /// Validates that `num` is a legal signal number before wrapping it, by
+    /// probing it with `sigaddset` against a temporary empty set.
+    fn try_from(num: c_int) -> Result<Signal, InvalidSignalError> {
+        unsafe {
+            let mut probe = MaybeUninit::uninit();
+            sys::sigemptyset(probe.as_mut_ptr());
+            let ret = sys::sigaddset(probe.as_mut_ptr(), num);
+
+            if ret < 0 {
+                Err(InvalidSignalError(()))
+            } else {
+                Ok(Signal::new(num))
+            }
+        }
+    }
+}
+
 macro_rules! declare_signals {
     {
         $(
@@ -115,6 +584,35 @@ macro_rules! declare_signals {
                 $( #[$attr] )*
                 $vis const $sig: Signal = Signal::new(libc::$sig);
             )*
+
+            /// Returns the canonical `SIGxxx` name of this signal, or `None` if
+            /// it is not one of the signals declared by this crate.
+            pub fn as_str(&self) -> Option<&'static str> {
+                $(
+                    $( #[$attr] )*
+                    {
+                        if self.0 == libc::$sig {
+                            return Some(stringify!($sig));
+                        }
+                    }
+                )*
+
+                None
+            }
+
+            fn from_name(name: &str) -> Option<Signal> {
+                $(
+                    $( #[$attr] )*
+                    {
+                        let canonical = stringify!($sig);
+                        if name == canonical || name == &canonical[3..] {
+                            return Some(Signal::$sig);
+                        }
+                    }
+                )*
+
+                None
+            }
         }
     }
 }
@@ -164,6 +662,30 @@ declare_signals! {
     pub const SIGUSR1;
     pub const SIGUSR2;
     pub const SIGWINCH;
+
+    // Platform specific
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub const SIGSTKFLT;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub const SIGPWR;
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    pub const SIGEMT;
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly"
+    ))]
+    pub const SIGINFO;
 }
 
 pub struct InvalidSignalError(());